@@ -1,6 +1,8 @@
 // Tauri API configuration for Axie Studio backend integration
-use tauri::command;
+use crate::settings::load_settings;
+use crate::telemetry::report_command_error;
 use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ApiConfig {
@@ -14,39 +16,32 @@ pub struct HealthResponse {
 }
 
 #[command]
-pub async fn get_backend_url() -> Result<String, String> {
-    // Return the production backend URL for Tauri builds
-    Ok("https://flow.axiestudio.se".to_string())
+pub async fn get_backend_url(app_handle: AppHandle) -> Result<String, String> {
+    Ok(load_settings(&app_handle).backend_url)
 }
 
+// Manual trigger (e.g. a "Check connection" button). Feeds the same state
+// machine the background monitor uses, so a manual check also updates the
+// tray and emits `backend-status-changed` for the frontend when the status
+// changes.
 #[command]
-pub async fn check_backend_health() -> Result<HealthResponse, String> {
-    let client = reqwest::Client::new();
-    
-    match client
-        .get("https://flow.axiestudio.se/health")
-        .timeout(std::time::Duration::from_secs(10))
-        .send()
-        .await
-    {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<HealthResponse>().await {
-                    Ok(health) => Ok(health),
-                    Err(e) => Err(format!("Failed to parse health response: {}", e)),
-                }
-            } else {
-                Err(format!("Backend health check failed with status: {}", response.status()))
-            }
-        }
-        Err(e) => Err(format!("Failed to connect to backend: {}", e)),
+pub async fn check_backend_health(app_handle: AppHandle) -> Result<HealthResponse, String> {
+    let result = crate::health_monitor::check(&app_handle).await;
+
+    if let Err(e) = &result {
+        report_command_error(&app_handle, "check_backend_health", e);
     }
+
+    result.map(|_| HealthResponse {
+        status: "ok".to_string(),
+    })
 }
 
 #[command]
-pub async fn get_api_config() -> Result<ApiConfig, String> {
+pub async fn get_api_config(app_handle: AppHandle) -> Result<ApiConfig, String> {
+    let settings = load_settings(&app_handle);
     Ok(ApiConfig {
-        backend_url: "https://flow.axiestudio.se".to_string(),
-        timeout: 30000, // 30 seconds
+        backend_url: settings.backend_url,
+        timeout: settings.timeout,
     })
 }
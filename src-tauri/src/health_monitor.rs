@@ -0,0 +1,141 @@
+// Background backend-health monitor: polls on an interval, backs off
+// exponentially while the backend stays unreachable, and emits
+// `backend-status-changed` events so the frontend never has to poll.
+use crate::settings::load_settings;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+const BASE_INTERVAL: Duration = Duration::from_secs(15);
+const MAX_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const DEGRADED_LATENCY_MS: u64 = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendStatus {
+    Online,
+    Degraded,
+    Offline,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusEvent {
+    pub status: BackendStatus,
+    pub latency_ms: Option<u64>,
+}
+
+#[derive(Default)]
+pub struct HealthState(Mutex<Option<BackendStatus>>);
+
+async fn probe(app_handle: &AppHandle) -> Result<Duration, String> {
+    let settings = load_settings(app_handle);
+    let client = reqwest::Client::new();
+    let start = Instant::now();
+
+    let response = client
+        .get(format!("{}/health", settings.backend_url))
+        .timeout(Duration::from_millis(settings.timeout))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to connect to backend: {}", e))?;
+
+    if response.status().is_success() {
+        Ok(start.elapsed())
+    } else {
+        Err(format!(
+            "Backend health check failed with status: {}",
+            response.status()
+        ))
+    }
+}
+
+// Runs a single probe and feeds the result into the shared state machine.
+// Used by both the background monitor and the manual `check_backend_health`
+// command, so both paths agree on what the tray and frontend are told.
+pub async fn check(app_handle: &AppHandle) -> Result<Duration, String> {
+    let result = probe(app_handle).await;
+    record_result(app_handle, &result);
+    result
+}
+
+fn status_for(result: &Result<Duration, String>) -> BackendStatus {
+    match result {
+        Ok(elapsed) if elapsed.as_millis() as u64 > DEGRADED_LATENCY_MS => BackendStatus::Degraded,
+        Ok(_) => BackendStatus::Online,
+        Err(_) => BackendStatus::Offline,
+    }
+}
+
+pub fn record_result(app_handle: &AppHandle, result: &Result<Duration, String>) {
+    let status = status_for(result);
+    let latency_ms = result.as_ref().ok().map(|d| d.as_millis() as u64);
+
+    // Swap in the new status and keep the previous one, so a transition that
+    // didn't actually change anything doesn't spam the frontend every poll.
+    let previous = app_handle
+        .try_state::<HealthState>()
+        .and_then(|state| state.0.lock().unwrap().replace(status));
+
+    crate::update_tray_status(app_handle, status);
+
+    if previous != Some(status) {
+        let _ = app_handle.emit_all("backend-status-changed", StatusEvent { status, latency_ms });
+    }
+}
+
+// Backs off exponentially while the backend stays unreachable, and resets to
+// the base interval as soon as a poll succeeds again.
+fn next_interval(current: Duration, failed: bool) -> Duration {
+    if failed {
+        std::cmp::min(current * 2, MAX_INTERVAL)
+    } else {
+        BASE_INTERVAL
+    }
+}
+
+// Starts the background poll loop. Call once from `setup()`.
+pub fn spawn(app_handle: AppHandle) {
+    app_handle.manage(HealthState::default());
+
+    tauri::async_runtime::spawn(async move {
+        let mut interval = BASE_INTERVAL;
+        loop {
+            let result = check(&app_handle).await;
+            interval = next_interval(interval, result.is_err());
+            tokio::time::sleep(interval).await;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_interval_doubles_on_failure() {
+        assert_eq!(next_interval(BASE_INTERVAL, true), BASE_INTERVAL * 2);
+        assert_eq!(next_interval(BASE_INTERVAL * 2, true), BASE_INTERVAL * 4);
+    }
+
+    #[test]
+    fn next_interval_caps_at_the_maximum() {
+        assert_eq!(next_interval(MAX_INTERVAL, true), MAX_INTERVAL);
+        assert_eq!(next_interval(MAX_INTERVAL / 2 + Duration::from_secs(1), true), MAX_INTERVAL);
+    }
+
+    #[test]
+    fn next_interval_resets_to_base_on_success() {
+        assert_eq!(next_interval(MAX_INTERVAL, false), BASE_INTERVAL);
+    }
+
+    #[test]
+    fn status_for_classifies_degraded_latency_separately_from_online() {
+        assert_eq!(status_for(&Ok(Duration::from_millis(10))), BackendStatus::Online);
+        assert_eq!(
+            status_for(&Ok(Duration::from_millis(DEGRADED_LATENCY_MS + 1))),
+            BackendStatus::Degraded
+        );
+        assert_eq!(status_for(&Err("down".to_string())), BackendStatus::Offline);
+    }
+}
@@ -3,6 +3,19 @@
 
 use tauri::{CustomMenuItem, SystemTray, SystemTrayMenu, SystemTrayEvent, Manager, AppHandle};
 use tauri::api::shell;
+use serde::Serialize;
+
+mod api;
+mod health_monitor;
+mod settings;
+mod shortcuts;
+mod telemetry;
+mod updater;
+use api::{check_backend_health, get_api_config, get_backend_url};
+use settings::{get_settings, set_backend_url};
+use shortcuts::{clear_global_shortcut, register_all as register_global_shortcuts, set_global_shortcut};
+use telemetry::set_telemetry_enabled;
+use updater::{check_for_update, install_update};
 
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
 #[tauri::command]
@@ -33,20 +46,95 @@ async fn show_main_window(app_handle: AppHandle) -> Result<(), String> {
     }
 }
 
+// Forwarded to the frontend when a second launch is redirected here, so the
+// UI can act on any CLI args/URLs (e.g. a deep link) the new process got.
+#[derive(Debug, Clone, Serialize)]
+struct SecondInstancePayload {
+    argv: Vec<String>,
+    cwd: String,
+}
+
+// Brings an already-running instance to the front instead of letting a second
+// process start. Mirrors the show/focus pattern used by the tray's left-click handler.
+fn focus_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_window("main") {
+        // The first instance may still be initializing its window; no-op if so.
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    update_tray_menu(app);
+}
+
 fn create_system_tray() -> SystemTray {
     let quit = CustomMenuItem::new("quit".to_string(), "Quit Axie Studio");
-    let show = CustomMenuItem::new("show".to_string(), "Show Axie Studio");
-    let hide = CustomMenuItem::new("hide".to_string(), "Hide Axie Studio");
-    
+    // "show"/"hide" are collapsed into a single toggle whose label reflects
+    // the window's current visibility; see update_tray_menu.
+    let toggle_visibility = CustomMenuItem::new("toggle_visibility".to_string(), "Hide Axie Studio");
+    let status = CustomMenuItem::new("status".to_string(), "Backend: Checking...").disabled();
+    let check_for_updates = CustomMenuItem::new("check_for_updates".to_string(), "Check for Updates");
+
     let tray_menu = SystemTrayMenu::new()
-        .add_item(show)
-        .add_item(hide)
+        .add_item(toggle_visibility)
+        .add_native_item(tauri::SystemTrayMenuItem::Separator)
+        .add_item(status)
+        .add_native_item(tauri::SystemTrayMenuItem::Separator)
+        .add_item(check_for_updates)
         .add_native_item(tauri::SystemTrayMenuItem::Separator)
         .add_item(quit);
-    
+
     SystemTray::new().with_menu(tray_menu)
 }
 
+// Flips the toggle item's label to match the window's actual visibility.
+// Called after the tray, global-shortcut, close-button and second-launch
+// paths show or hide the window; the `show_main_window` command does not call it.
+fn update_tray_menu(app: &AppHandle) {
+    let Some(window) = app.get_window("main") else {
+        return;
+    };
+    let is_visible = window.is_visible().unwrap_or(true);
+    let label = if is_visible { "Hide Axie Studio" } else { "Show Axie Studio" };
+    let _ = app.tray_handle().get_item("toggle_visibility").set_title(label);
+}
+
+// Shows+focuses or hides the main window depending on its current
+// visibility. Shared by the tray's toggle item and the global shortcut.
+pub(crate) fn toggle_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_window("main") {
+        if window.is_visible().unwrap_or(true) {
+            let _ = window.hide();
+        } else {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+    update_tray_menu(app);
+}
+
+// Reflects the latest backend health status in the tray's status line and
+// icon color so a dropped (or slow) connection is visible without opening
+// the window. Degraded gets its own label/icon rather than being folded
+// into "Online".
+pub(crate) fn update_tray_status(app: &AppHandle, status: health_monitor::BackendStatus) {
+    use health_monitor::BackendStatus;
+
+    let label = match status {
+        BackendStatus::Online => "Backend: Online",
+        BackendStatus::Degraded => "Backend: Degraded",
+        BackendStatus::Offline => "Backend: Offline",
+    };
+    let _ = app.tray_handle().get_item("status").set_title(label);
+
+    let icon_path = match status {
+        BackendStatus::Online => "icons/tray-online.png",
+        BackendStatus::Degraded => "icons/tray-degraded.png",
+        BackendStatus::Offline => "icons/tray-offline.png",
+    };
+    if let Some(resource) = app.path_resolver().resolve_resource(icon_path) {
+        let _ = app.tray_handle().set_icon(tauri::Icon::File(resource));
+    }
+}
+
 fn handle_system_tray_event(app: &AppHandle, event: SystemTrayEvent) {
     match event {
         SystemTrayEvent::LeftClick {
@@ -59,22 +147,21 @@ fn handle_system_tray_event(app: &AppHandle, event: SystemTrayEvent) {
                 let _ = window.show();
                 let _ = window.set_focus();
             }
+            update_tray_menu(app);
         }
         SystemTrayEvent::MenuItemClick { id, .. } => {
             match id.as_str() {
                 "quit" => {
                     std::process::exit(0);
                 }
-                "show" => {
-                    if let Some(window) = app.get_window("main") {
-                        let _ = window.show();
-                        let _ = window.set_focus();
-                    }
+                "toggle_visibility" => {
+                    toggle_main_window(app);
                 }
-                "hide" => {
-                    if let Some(window) = app.get_window("main") {
-                        let _ = window.hide();
-                    }
+                "check_for_updates" => {
+                    let app_handle = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        let _ = check_for_update(app_handle).await;
+                    });
                 }
                 _ => {}
             }
@@ -84,16 +171,50 @@ fn handle_system_tray_event(app: &AppHandle, event: SystemTrayEvent) {
 }
 
 fn main() {
+    // Re-exec'd as the out-of-process minidump server; see
+    // `telemetry::install_native_crash_handler`. Never returns.
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(flag_pos) = args.iter().position(|a| a == telemetry::CRASH_SERVER_FLAG) {
+        let socket_name = args.get(flag_pos + 1).cloned().unwrap_or_default();
+        telemetry::run_crash_server(&socket_name, std::env::temp_dir());
+    }
+
     tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            // A second launch (or a URL open) was attempted; surface the
+            // existing window and forward its args/URLs to the frontend
+            // instead of letting a new process take over.
+            focus_main_window(app);
+            let _ = app.emit_all("second-instance", SecondInstancePayload { argv, cwd });
+        }))
         .system_tray(create_system_tray())
         .on_system_tray_event(handle_system_tray_event)
         .invoke_handler(tauri::generate_handler![
             greet,
             open_external_url,
             get_app_version,
-            show_main_window
+            show_main_window,
+            check_for_update,
+            install_update,
+            check_backend_health,
+            get_backend_url,
+            get_api_config,
+            get_settings,
+            set_backend_url,
+            set_global_shortcut,
+            clear_global_shortcut,
+            set_telemetry_enabled
         ])
         .setup(|app| {
+            // Logging and crash reporting first, so every later setup step is covered.
+            if let Err(e) = telemetry::init_logging(&app.handle()) {
+                eprintln!("Failed to initialize logging: {}", e);
+            }
+            telemetry::install_panic_hook(app.handle());
+            if let Err(e) = telemetry::install_native_crash_handler(&app.handle()) {
+                eprintln!("Failed to install native crash handler: {}", e);
+            }
+
             // Set up the main window
             let window = app.get_window("main").unwrap();
             
@@ -106,7 +227,23 @@ fn main() {
             
             // Show window on startup
             window.show().unwrap();
-            
+
+            // Re-register persisted global shortcuts (default: toggle window).
+            register_global_shortcuts(&app.handle());
+
+            // Check for a newer signed build on startup, then on a timer.
+            let app_handle = app.handle();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    let _ = check_for_update(app_handle.clone()).await;
+                    tokio::time::sleep(std::time::Duration::from_secs(6 * 60 * 60)).await;
+                }
+            });
+
+            // Background health monitor: polls with backoff and emits
+            // `backend-status-changed` so the frontend never has to poll.
+            health_monitor::spawn(app.handle());
+
             Ok(())
         })
         .on_window_event(|event| match event.event() {
@@ -114,6 +251,7 @@ fn main() {
                 // Hide the window instead of closing on close button click
                 event.window().hide().unwrap();
                 api.prevent_close();
+                update_tray_menu(&event.window().app_handle());
             }
             _ => {}
         })
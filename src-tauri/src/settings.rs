@@ -0,0 +1,105 @@
+// Persisted app settings (backend URL, request timeout) so self-hosted and
+// staging users don't have to rebuild to point at a different backend.
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tauri::{command, AppHandle, Manager};
+
+const SETTINGS_FILE: &str = "settings.json";
+const DEFAULT_BACKEND_URL: &str = "https://flow.axiestudio.se";
+const DEFAULT_TIMEOUT_MS: u64 = 30000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Settings {
+    pub backend_url: String,
+    pub timeout: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            backend_url: DEFAULT_BACKEND_URL.to_string(),
+            timeout: DEFAULT_TIMEOUT_MS,
+        }
+    }
+}
+
+fn settings_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path_resolver()
+        .app_config_dir()
+        .ok_or_else(|| "Failed to resolve app config directory".to_string())?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create app config directory: {}", e))?;
+    Ok(dir.join(SETTINGS_FILE))
+}
+
+pub fn load_settings(app_handle: &AppHandle) -> Settings {
+    let Ok(path) = settings_path(app_handle) else {
+        return Settings::default();
+    };
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_settings(app_handle: &AppHandle, settings: &Settings) -> Result<(), String> {
+    let path = settings_path(app_handle)?;
+    let contents = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write settings: {}", e))
+}
+
+async fn probe_backend(url: &str, timeout: u64) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("{}/health", url))
+        .timeout(std::time::Duration::from_millis(timeout))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to connect to backend: {}", e))?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Backend health check failed with status: {}",
+            response.status()
+        ))
+    }
+}
+
+#[command]
+pub async fn get_settings(app_handle: AppHandle) -> Result<Settings, String> {
+    Ok(load_settings(&app_handle))
+}
+
+#[command]
+pub async fn set_backend_url(app_handle: AppHandle, url: String) -> Result<(), String> {
+    let mut settings = load_settings(&app_handle);
+    probe_backend(&url, settings.timeout).await?;
+    settings.backend_url = url;
+    save_settings(&app_handle, &settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_settings_round_trip_through_json() {
+        let settings = Settings::default();
+        let serialized = serde_json::to_string(&settings).unwrap();
+        let deserialized: Settings = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.backend_url, settings.backend_url);
+        assert_eq!(deserialized.timeout, settings.timeout);
+    }
+
+    #[test]
+    fn default_settings_point_at_the_public_backend() {
+        let settings = Settings::default();
+        assert_eq!(settings.backend_url, DEFAULT_BACKEND_URL);
+        assert_eq!(settings.timeout, DEFAULT_TIMEOUT_MS);
+    }
+}
@@ -0,0 +1,136 @@
+// Global hotkey bindings, persisted so they survive restarts and can be
+// re-registered on startup. Bindings map an action name to an accelerator
+// string understood by tauri's GlobalShortcutManager (e.g. "CmdOrCtrl+Shift+A").
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tauri::{command, AppHandle, GlobalShortcutManager, Manager};
+
+const BINDINGS_FILE: &str = "shortcuts.json";
+pub const TOGGLE_WINDOW_ACTION: &str = "toggle_window";
+const DEFAULT_TOGGLE_ACCELERATOR: &str = "CmdOrCtrl+Shift+A";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutBindings(HashMap<String, String>);
+
+impl Default for ShortcutBindings {
+    fn default() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert(
+            TOGGLE_WINDOW_ACTION.to_string(),
+            DEFAULT_TOGGLE_ACCELERATOR.to_string(),
+        );
+        ShortcutBindings(bindings)
+    }
+}
+
+fn bindings_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path_resolver()
+        .app_config_dir()
+        .ok_or_else(|| "Failed to resolve app config directory".to_string())?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create app config directory: {}", e))?;
+    Ok(dir.join(BINDINGS_FILE))
+}
+
+fn load_bindings(app_handle: &AppHandle) -> ShortcutBindings {
+    let Ok(path) = bindings_path(app_handle) else {
+        return ShortcutBindings::default();
+    };
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_bindings(app_handle: &AppHandle, bindings: &ShortcutBindings) -> Result<(), String> {
+    let path = bindings_path(app_handle)?;
+    let contents = serde_json::to_string_pretty(bindings)
+        .map_err(|e| format!("Failed to serialize shortcut bindings: {}", e))?;
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write shortcut bindings: {}", e))
+}
+
+fn dispatch_action(app_handle: &AppHandle, action: &str) {
+    match action {
+        TOGGLE_WINDOW_ACTION => crate::toggle_main_window(app_handle),
+        _ => {}
+    }
+}
+
+fn register(app_handle: &AppHandle, action: &str, accelerator: &str) -> Result<(), String> {
+    let mut manager = app_handle.global_shortcut_manager();
+    let action = action.to_string();
+    let app_handle_for_callback = app_handle.clone();
+    manager
+        .register(accelerator, move || {
+            dispatch_action(&app_handle_for_callback, &action);
+        })
+        .map_err(|e| format!("Failed to register shortcut \"{}\": {}", accelerator, e))
+}
+
+// Registers every persisted binding; called once on startup. Skips (but logs)
+// any accelerator that's already taken by another application.
+pub fn register_all(app_handle: &AppHandle) {
+    let bindings = load_bindings(app_handle);
+    for (action, accelerator) in bindings.0.iter() {
+        if let Err(e) = register(app_handle, action, accelerator) {
+            eprintln!("{}", e);
+        }
+    }
+}
+
+#[command]
+pub async fn set_global_shortcut(
+    app_handle: AppHandle,
+    action: String,
+    accelerator: String,
+) -> Result<(), String> {
+    let mut bindings = load_bindings(&app_handle);
+
+    if let Some(existing) = bindings.0.get(&action) {
+        let _ = app_handle.global_shortcut_manager().unregister(existing);
+    }
+
+    register(&app_handle, &action, &accelerator)?;
+
+    bindings.0.insert(action, accelerator);
+    save_bindings(&app_handle, &bindings)
+}
+
+#[command]
+pub async fn clear_global_shortcut(app_handle: AppHandle, action: String) -> Result<(), String> {
+    let mut bindings = load_bindings(&app_handle);
+
+    if let Some(accelerator) = bindings.0.remove(&action) {
+        app_handle
+            .global_shortcut_manager()
+            .unregister(&accelerator)
+            .map_err(|e| format!("Failed to unregister shortcut \"{}\": {}", accelerator, e))?;
+    }
+
+    save_bindings(&app_handle, &bindings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_bindings_round_trip_through_json() {
+        let bindings = ShortcutBindings::default();
+        let serialized = serde_json::to_string(&bindings).unwrap();
+        let deserialized: ShortcutBindings = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.0, bindings.0);
+    }
+
+    #[test]
+    fn default_bindings_bind_toggle_window() {
+        let bindings = ShortcutBindings::default();
+        assert_eq!(
+            bindings.0.get(TOGGLE_WINDOW_ACTION).map(String::as_str),
+            Some(DEFAULT_TOGGLE_ACCELERATOR)
+        );
+    }
+}
@@ -0,0 +1,253 @@
+// Crash/error reporting and structured logging. Off by default — a user
+// must explicitly opt in via `set_telemetry_enabled` before anything leaves
+// the machine.
+use minidumper::{Client as MinidumperClient, LoopAction, MinidumpBinary, Server as MinidumperServer, ServerHandler};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use tauri::{command, AppHandle, Manager};
+
+const TELEMETRY_FILE: &str = "telemetry.json";
+
+// CLI flag this binary re-execs itself with to run as the out-of-process
+// minidump server. Checked in `main()` before the Tauri app is built.
+pub const CRASH_SERVER_FLAG: &str = "--crash-handler-server";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TelemetryConfig {
+    enabled: bool,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        TelemetryConfig { enabled: false }
+    }
+}
+
+// Reports go to the same backend `set_backend_url` points at, so a
+// self-hosted user's crash/error data never leaves their own instance.
+fn dsn_url(app_handle: &AppHandle) -> String {
+    let backend_url = crate::settings::load_settings(app_handle).backend_url;
+    format!("{}/telemetry/report", backend_url.trim_end_matches('/'))
+}
+
+#[derive(Debug, Serialize)]
+struct CrashReport<'a> {
+    app_version: &'a str,
+    target: &'a str,
+    kind: &'a str,
+    message: String,
+}
+
+fn config_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path_resolver()
+        .app_config_dir()
+        .ok_or_else(|| "Failed to resolve app config directory".to_string())?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create app config directory: {}", e))?;
+    Ok(dir.join(TELEMETRY_FILE))
+}
+
+fn load_config(app_handle: &AppHandle) -> TelemetryConfig {
+    let Ok(path) = config_path(app_handle) else {
+        return TelemetryConfig::default();
+    };
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_config(app_handle: &AppHandle, config: &TelemetryConfig) -> Result<(), String> {
+    let path = config_path(app_handle)?;
+    let contents = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize telemetry config: {}", e))?;
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write telemetry config: {}", e))
+}
+
+// Initializes the rotating-file logger. Writes to the app's log directory
+// and mirrors to stdout in debug builds so `cargo tauri dev` stays useful.
+pub fn init_logging(app_handle: &AppHandle) -> Result<(), String> {
+    let log_dir = app_handle
+        .path_resolver()
+        .app_log_dir()
+        .ok_or_else(|| "Failed to resolve app log directory".to_string())?;
+    std::fs::create_dir_all(&log_dir)
+        .map_err(|e| format!("Failed to create app log directory: {}", e))?;
+
+    let mut dispatch = fern::Dispatch::new()
+        .format(|out, message, record| {
+            out.finish(format_args!(
+                "[{} {} {}] {}",
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+                record.level(),
+                record.target(),
+                message
+            ))
+        })
+        .level(log::LevelFilter::Info)
+        .chain(fern::DateBased::new(log_dir.join("axiestudio."), "%Y-%m-%d.log"));
+
+    if cfg!(debug_assertions) {
+        dispatch = dispatch.chain(std::io::stdout());
+    }
+
+    dispatch
+        .apply()
+        .map_err(|e| format!("Failed to initialize logger: {}", e))
+}
+
+async fn upload_report(dsn: &str, report: &CrashReport<'_>) {
+    let client = reqwest::Client::new();
+    let _ = client
+        .post(dsn)
+        .json(report)
+        .timeout(std::time::Duration::from_secs(10))
+        .send()
+        .await;
+}
+
+struct MinidumpServerHandler {
+    dump_dir: PathBuf,
+}
+
+impl ServerHandler for MinidumpServerHandler {
+    fn create_minidump_file(&self) -> Result<(std::fs::File, PathBuf), std::io::Error> {
+        let path = self.dump_dir.join(format!(
+            "axiestudio-{}.dmp",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default()
+        ));
+        let file = std::fs::File::create(&path)?;
+        Ok((file, path))
+    }
+
+    fn on_minidump_created(&self, result: Result<MinidumpBinary, minidumper::Error>) -> LoopAction {
+        match result {
+            Ok(mut binary) => {
+                use std::io::Write;
+                let _ = binary.file.flush();
+                log::error!("native crash captured: {}", binary.path.display());
+            }
+            Err(e) => log::error!("failed to write minidump: {}", e),
+        }
+        LoopAction::Exit
+    }
+
+    fn on_message(&self, _kind: u32, _buffer: Vec<u8>) {}
+}
+
+// Runs this process as the out-of-process minidump server and never
+// returns. Invoked from `main()` when re-exec'd with `CRASH_SERVER_FLAG`,
+// so a crash in the main process (which can't safely do much work while
+// crashing) has a separate, healthy process to hand the dump to.
+pub fn run_crash_server(socket_name: &str, dump_dir: PathBuf) -> ! {
+    let mut server =
+        MinidumperServer::with_name(socket_name).expect("failed to start crash handler server");
+    let shutdown = AtomicBool::new(false);
+    server
+        .run(Box::new(MinidumpServerHandler { dump_dir }), &shutdown, None)
+        .expect("crash handler server loop failed");
+    std::process::exit(0);
+}
+
+// Spawns the out-of-process minidump server and attaches an in-process
+// crash handler that hands native crashes (segfaults, illegal
+// instructions, aborts) off to it for capture. Complements
+// `install_panic_hook`, which only covers Rust panics.
+pub fn install_native_crash_handler(app_handle: &AppHandle) -> Result<(), String> {
+    let socket_name = format!("axiestudio-crash-{}", std::process::id());
+    let exe = std::env::current_exe()
+        .map_err(|e| format!("Failed to resolve current executable: {}", e))?;
+
+    std::process::Command::new(exe)
+        .arg(CRASH_SERVER_FLAG)
+        .arg(&socket_name)
+        .spawn()
+        .map_err(|e| format!("Failed to spawn crash handler server: {}", e))?;
+
+    // Give the server a moment to bind its socket before we connect to it.
+    std::thread::sleep(std::time::Duration::from_millis(200));
+
+    let client = Arc::new(
+        MinidumperClient::with_name(&socket_name)
+            .map_err(|e| format!("Failed to connect to crash handler server: {}", e))?,
+    );
+
+    let handler_client = client.clone();
+    let handler = crash_handler::CrashHandler::attach(unsafe {
+        crash_handler::make_crash_event(move |context: &crash_handler::CrashContext| {
+            let _ = handler_client.send_message(1, b"native-crash".to_vec());
+            handler_client.request_dump(context).is_ok()
+        })
+    })
+    .map_err(|e| format!("Failed to install native crash handler: {}", e))?;
+
+    // Both must outlive `setup()`; nothing else holds a reference to them.
+    std::mem::forget(handler);
+    app_handle.manage(client);
+
+    Ok(())
+}
+
+// Captures panics, logs them, and forwards them to the configured DSN when
+// telemetry is enabled. Native crashes are handled separately by
+// `install_native_crash_handler`.
+pub fn install_panic_hook(app_handle: AppHandle) {
+    std::panic::set_hook(Box::new(move |info| {
+        let message = info.to_string();
+        log::error!("panic: {}", message);
+
+        let config = load_config(&app_handle);
+        if !config.enabled {
+            return;
+        }
+
+        let app_version = env!("CARGO_PKG_VERSION").to_string();
+        let dsn = dsn_url(&app_handle);
+        tauri::async_runtime::spawn(async move {
+            let report = CrashReport {
+                app_version: &app_version,
+                target: std::env::consts::OS,
+                kind: "panic",
+                message,
+            };
+            upload_report(&dsn, &report).await;
+        });
+    }));
+}
+
+// Reports a recoverable command failure (e.g. a failed health check) when
+// telemetry is enabled, tagged with the running app version for triage.
+pub fn report_command_error(app_handle: &AppHandle, command: &str, error: &str) {
+    let config = load_config(app_handle);
+    if !config.enabled {
+        return;
+    }
+
+    log::error!("command \"{}\" failed: {}", command, error);
+
+    let app_version = env!("CARGO_PKG_VERSION").to_string();
+    let message = format!("{}: {}", command, error);
+    let dsn = dsn_url(app_handle);
+    tauri::async_runtime::spawn(async move {
+        let report = CrashReport {
+            app_version: &app_version,
+            target: std::env::consts::OS,
+            kind: "command_error",
+            message,
+        };
+        upload_report(&dsn, &report).await;
+    });
+}
+
+#[command]
+pub async fn set_telemetry_enabled(app_handle: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut config = load_config(&app_handle);
+    config.enabled = enabled;
+    save_config(&app_handle, &config)
+}
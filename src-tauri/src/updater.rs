@@ -0,0 +1,273 @@
+// Auto-update subsystem: checks the backend's update manifest against the
+// running build and, when newer, downloads/verifies/installs it.
+use base64::Engine;
+use ed25519_dalek::{Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager};
+
+// Public half of the release-signing keypair. The backend's update manifest
+// carries a base64 ed25519 signature over the downloaded artifact, produced
+// by the matching private key in the release pipeline.
+const UPDATE_PUBLIC_KEY: [u8; 32] = [
+    0x4c, 0x9f, 0x21, 0x7a, 0x8e, 0x03, 0x5d, 0x6b, 0x11, 0xc4, 0x9a, 0x72, 0x2f, 0x58, 0xe1, 0x0d,
+    0x63, 0xb7, 0x84, 0x2e, 0xfa, 0x19, 0x4d, 0x96, 0x3c, 0x8a, 0x55, 0x70, 0xd1, 0x27, 0xbe, 0x46,
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub notes: String,
+    pub download_url: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct UpdateManifest {
+    url: String,
+    version: String,
+    notes: String,
+    signature: String,
+}
+
+fn manifest_url(backend_url: &str, target: &str, current_version: &str) -> String {
+    format!(
+        "{}/updates/{}/{}",
+        backend_url.trim_end_matches('/'),
+        target,
+        current_version
+    )
+}
+
+async fn fetch_manifest(
+    backend_url: &str,
+    target: &str,
+    current_version: &str,
+) -> Result<Option<UpdateManifest>, String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(manifest_url(backend_url, target, current_version))
+        .timeout(std::time::Duration::from_secs(10))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach update server: {}", e))?;
+
+    if response.status() == reqwest::StatusCode::NO_CONTENT {
+        // Backend returns 204 when the running version is already current.
+        return Ok(None);
+    }
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Update manifest request failed with status: {}",
+            response.status()
+        ));
+    }
+
+    response
+        .json::<UpdateManifest>()
+        .await
+        .map(Some)
+        .map_err(|e| format!("Failed to parse update manifest: {}", e))
+}
+
+#[command]
+pub async fn check_for_update(app_handle: AppHandle) -> Result<Option<UpdateInfo>, String> {
+    let current_version = env!("CARGO_PKG_VERSION").to_string();
+    let target = tauri::updater::target().unwrap_or_else(|| std::env::consts::OS.to_string());
+    // Self-hosted/staging users get their own update manifest, not the
+    // public one, since this reads from the same store `set_backend_url` writes.
+    let backend_url = crate::settings::load_settings(&app_handle).backend_url;
+
+    let manifest = fetch_manifest(&backend_url, &target, &current_version).await?;
+
+    match manifest {
+        Some(manifest) => {
+            let info = UpdateInfo {
+                version: manifest.version,
+                notes: manifest.notes,
+                download_url: manifest.url,
+                signature: manifest.signature,
+            };
+            let _ = app_handle.emit_all("update-available", &info);
+            Ok(Some(info))
+        }
+        None => Ok(None),
+    }
+}
+
+#[command]
+pub async fn install_update(app_handle: AppHandle) -> Result<(), String> {
+    // Re-checks so we always install the manifest that's current at call time.
+    let update = check_for_update(app_handle.clone())
+        .await?
+        .ok_or_else(|| "No update is available to install".to_string())?;
+
+    let client = reqwest::Client::new();
+    let bytes = client
+        .get(&update.download_url)
+        .timeout(std::time::Duration::from_secs(120))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download update: {}", e))?
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read update payload: {}", e))?;
+
+    verify_signature(&bytes, &update.signature)?;
+
+    let installer_path = stage_installer(&bytes, &update.version)?;
+    apply_installer(&app_handle, &installer_path)
+}
+
+// Verifies the downloaded artifact against the release-signing public key
+// before it's ever written to disk or executed.
+fn verify_signature(payload: &[u8], signature_b64: &str) -> Result<(), String> {
+    verify_with_key(payload, signature_b64, &UPDATE_PUBLIC_KEY)
+}
+
+fn verify_with_key(payload: &[u8], signature_b64: &str, public_key: &[u8; 32]) -> Result<(), String> {
+    let verifying_key = VerifyingKey::from_bytes(public_key)
+        .map_err(|e| format!("Invalid update public key: {}", e))?;
+
+    let signature_bytes = base64::engine::general_purpose::STANDARD
+        .decode(signature_b64)
+        .map_err(|e| format!("Failed to decode update signature: {}", e))?;
+    let signature = Signature::from_slice(&signature_bytes)
+        .map_err(|e| format!("Malformed update signature: {}", e))?;
+
+    verifying_key
+        .verify_strict(payload, &signature)
+        .map_err(|_| "Update payload failed signature verification".to_string())
+}
+
+fn installer_file_name(version: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("axiestudio-{}-setup.exe", version)
+    } else if cfg!(target_os = "macos") {
+        format!("axiestudio-{}.pkg", version)
+    } else {
+        format!("axiestudio-{}.AppImage", version)
+    }
+}
+
+fn stage_installer(payload: &[u8], version: &str) -> Result<std::path::PathBuf, String> {
+    let path = std::env::temp_dir().join(installer_file_name(version));
+    std::fs::write(&path, payload)
+        .map_err(|e| format!("Failed to stage update installer: {}", e))?;
+    Ok(path)
+}
+
+// Actually applies the staged, signature-verified artifact. Each platform
+// hands off differently, since a running binary can't simply be overwritten
+// and `restart()`-ing before the install finishes would just relaunch the
+// unchanged old build:
+// - Linux: the AppImage can be swapped in place while running, so we copy
+//   synchronously and only then restart into the new file.
+// - Windows: the running exe is locked, so we launch the silent installer
+//   and quit immediately; it relaunches the app once files are replaced.
+// - macOS: installing a .pkg needs admin authorization that only the
+//   interactive Installer.app can prompt for, so we hand off to `open`
+//   and quit rather than trying to silently elevate ourselves.
+fn apply_installer(app_handle: &AppHandle, path: &std::path::Path) -> Result<(), String> {
+    // Only the Linux handoff needs the app handle (to restart in-process);
+    // silence the unused-parameter warning on the other targets.
+    #[cfg(not(target_os = "linux"))]
+    let _ = app_handle;
+
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let current_exe = std::env::current_exe()
+            .map_err(|e| format!("Failed to resolve current executable: {}", e))?;
+        std::fs::copy(path, &current_exe)
+            .map_err(|e| format!("Failed to replace AppImage: {}", e))?;
+        let mut perms = std::fs::metadata(&current_exe)
+            .map_err(|e| format!("Failed to read executable metadata: {}", e))?
+            .permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&current_exe, perms)
+            .map_err(|e| format!("Failed to mark AppImage executable: {}", e))?;
+
+        tauri::api::process::restart(&app_handle.env());
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new(path)
+            .arg("/S")
+            .spawn()
+            .map_err(|e| format!("Failed to launch installer: {}", e))?;
+
+        std::process::exit(0);
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg(path)
+            .spawn()
+            .map_err(|e| format!("Failed to open installer: {}", e))?;
+
+        std::process::exit(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn test_keypair() -> (SigningKey, [u8; 32]) {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = signing_key.verifying_key().to_bytes();
+        (signing_key, public_key)
+    }
+
+    #[test]
+    fn verify_with_key_accepts_a_genuine_signature() {
+        let (signing_key, public_key) = test_keypair();
+        let payload = b"axiestudio-update-payload";
+        let signature = signing_key.sign(payload);
+        let signature_b64 = base64::engine::general_purpose::STANDARD.encode(signature.to_bytes());
+
+        assert!(verify_with_key(payload, &signature_b64, &public_key).is_ok());
+    }
+
+    #[test]
+    fn verify_with_key_rejects_a_tampered_payload() {
+        let (signing_key, public_key) = test_keypair();
+        let payload = b"axiestudio-update-payload";
+        let signature = signing_key.sign(payload);
+        let signature_b64 = base64::engine::general_purpose::STANDARD.encode(signature.to_bytes());
+
+        assert!(verify_with_key(b"a different payload", &signature_b64, &public_key).is_err());
+    }
+
+    #[test]
+    fn verify_with_key_rejects_malformed_signature() {
+        let (_signing_key, public_key) = test_keypair();
+        assert!(verify_with_key(b"payload", "not-valid-base64!!", &public_key).is_err());
+    }
+
+    #[test]
+    fn manifest_url_trims_trailing_slash_on_backend_url() {
+        assert_eq!(
+            manifest_url("https://example.com/", "macos", "1.2.3"),
+            "https://example.com/updates/macos/1.2.3"
+        );
+    }
+
+    #[test]
+    fn installer_file_name_matches_current_target_convention() {
+        let name = installer_file_name("1.2.3");
+        assert!(name.contains("1.2.3"));
+        if cfg!(target_os = "windows") {
+            assert!(name.ends_with("-setup.exe"));
+        } else if cfg!(target_os = "macos") {
+            assert!(name.ends_with(".pkg"));
+        } else {
+            assert!(name.ends_with(".AppImage"));
+        }
+    }
+}